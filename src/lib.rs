@@ -69,6 +69,10 @@
 
 use std::cmp::min;
 
+mod render;
+
+pub use render::{ConnectionFilter, Highlight, RenderConfig};
+
 /// A `Position` depicts the location of a row.
 #[derive(Debug)]
 pub struct Position {
@@ -207,6 +211,103 @@ impl Crossbar {
         count / 2
     }
 
+    /// Returns a [Graphviz DOT](https://graphviz.org/doc/info/lang.html)
+    /// representation of the `K_n` graph realized by a crossbar
+    /// with `count` terminals.
+    ///
+    /// One node is emitted per terminal and one edge per
+    /// `Connection`, labeled with its `col_idx` and the `block_idx`
+    /// of each of its two endpoints (a connection can span two
+    /// adjacent blocks, e.g. in `full_block_forward`'s wrap case).
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - Number of terminals in the crossbar.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let dot = xbar::Crossbar::to_dot(4);
+    /// assert!(dot.starts_with("graph K4 {"));
+    /// ```
+    pub fn to_dot(count: usize) -> String {
+        let mut out = format!("graph K{} {{\n", count);
+        for i in 0..count {
+            out.push_str(&format!("    {};\n", i));
+        }
+        for con in Self::new(count) {
+            out.push_str(&format!(
+                "    {} -- {} [label=\"col={}, blocks={}-{}\"];\n",
+                con.start.row_idx,
+                con.end.row_idx,
+                con.col_idx,
+                con.start.block_idx,
+                con.end.block_idx
+            ));
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// Returns the symmetric `count x count` adjacency matrix of the
+    /// `K_n` graph realized by a crossbar with `count` terminals.
+    ///
+    /// `adjacency(count)[a][b]` is `1` if terminals `a` and `b` are
+    /// connected (always true for `a != b`), and `0` on the diagonal.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - Number of terminals in the crossbar.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let mat = xbar::Crossbar::adjacency(4);
+    /// assert_eq!(mat[0][1], 1);
+    /// assert_eq!(mat[0][0], 0);
+    /// ```
+    pub fn adjacency(count: usize) -> Vec<Vec<u8>> {
+        let mut mat = vec![vec![0u8; count]; count];
+        for con in Self::new(count) {
+            let (a, b) = (con.start.row_idx, con.end.row_idx);
+            mat[a][b] = 1;
+            mat[b][a] = 1;
+        }
+        mat
+    }
+
+    /// Returns the unique `Connection` realizing the edge between
+    /// terminals `a` and `b` in a crossbar with `count` terminals,
+    /// or `None` if `a` and `b` are out of range or equal.
+    ///
+    /// Uses the same closed-form logic as `full_block`/`half_block`,
+    /// so the lookup is cheap even for large `count`.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - Number of terminals in the crossbar.
+    /// * `a` - First terminal.
+    /// * `b` - Second terminal.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let con = xbar::Crossbar::connection_between(10, 2, 5).unwrap();
+    /// assert_eq!(2, con.start.row_idx.min(con.end.row_idx));
+    /// ```
+    pub fn connection_between(count: usize, a: usize, b: usize) -> Option<Connection> {
+        if a >= count || b >= count || a == b {
+            return None;
+        }
+        let d = (b + count - a) % count;
+        let half = count / 2;
+        if 2 * half == count && d == half {
+            return Some(Self::half_block(count, half, min(a, b)));
+        }
+        let (i, j) = if d < count - d { (d, a) } else { (count - d, b) };
+        Some(Self::full_block(count, i, j))
+    }
+
     #[inline]
     fn b2i(b: bool) -> usize {
         if b {
@@ -270,6 +371,46 @@ impl Crossbar {
             self.outer_idx += 1;
         }
     }
+
+    /// Total number of connections a crossbar with `count`
+    /// terminals produces: `K_count` has exactly `count * (count -
+    /// 1) / 2` edges.
+    #[inline]
+    fn total(count: usize) -> usize {
+        count * (count - 1) / 2
+    }
+
+    /// Number of connections already produced by this iterator.
+    #[inline]
+    fn consumed(&self) -> usize {
+        let mut sum = 0;
+        let mut i = 1;
+        while i < self.outer_idx {
+            sum += if 2 * i < self.count { self.count } else { i };
+            i += 1;
+        }
+        sum + self.inner_idx
+    }
+
+    /// Locates the `(outer_idx, inner_idx)` pair for the connection
+    /// at global index `idx` (counting from the very first
+    /// connection), without iterating. Returns `None` if `idx` is
+    /// out of range.
+    #[inline]
+    fn locate(count: usize, mut idx: usize) -> Option<(usize, usize)> {
+        let mut i = 1;
+        while 2 * i < count {
+            if idx < count {
+                return Some((i, idx));
+            }
+            idx -= count;
+            i += 1;
+        }
+        if 2 * i == count && idx < i {
+            return Some((i, idx));
+        }
+        None
+    }
 }
 
 impl Iterator for Crossbar {
@@ -290,8 +431,39 @@ impl Iterator for Crossbar {
             Some(conn)
         }
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = Self::total(self.count).saturating_sub(self.consumed());
+        (remaining, Some(remaining))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let target = self.consumed() + n;
+        match Self::locate(self.count, target) {
+            Some((i, j)) => {
+                let inner_lim = if 2 * i == self.count { i } else { self.count };
+                let conn = if 2 * i == self.count {
+                    Self::half_block(self.count, i, j)
+                } else {
+                    Self::full_block(self.count, i, j)
+                };
+                self.outer_idx = i;
+                self.inner_idx = j;
+                self.step(inner_lim);
+                Some(conn)
+            }
+            None => {
+                self.outer_idx = self.count / 2 + 1;
+                self.inner_idx = 0;
+                None
+            }
+        }
+    }
 }
 
+impl ExactSizeIterator for Crossbar {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,241 @@
+//! SVG rendering of a [`Crossbar`](crate::Crossbar) as a [`svg::Document`].
+
+use std::io::{self, Write};
+
+use svg::node::element::path::Data;
+use svg::node::{element::Path, element::Text as TextBox, Text};
+use svg::Document;
+
+use crate::{Connection, Crossbar};
+
+/// Configuration for [`Crossbar::render`].
+///
+/// All dimensions are in SVG user units (pixels). Use
+/// [`RenderConfig::default`] to get the classic look and override
+/// individual fields as needed.
+#[derive(Debug, Clone)]
+pub struct RenderConfig {
+    /// Width of a single column, in pixels.
+    pub block_w: usize,
+    /// Height of a single row, in pixels.
+    pub block_h: usize,
+    /// Horizontal margin around the drawing.
+    pub margin_x: usize,
+    /// Vertical margin around the drawing.
+    pub margin_y: usize,
+    /// Extra horizontal padding reserved for the terminal labels.
+    pub left_text_pad: usize,
+    /// Stroke color used for the connections.
+    pub stroke_color: String,
+    /// Stroke color used for the outer frame.
+    pub frame_stroke_color: String,
+    /// Fill color used for the frame.
+    pub fill_color: String,
+    /// Fill color used for the terminal labels.
+    pub text_color: String,
+    /// Stroke width used for the frame and the connections.
+    pub stroke_width: usize,
+    /// Font family used for the terminal labels.
+    pub font_family: String,
+    /// Font size, in pixels, used for the terminal labels.
+    pub font_size: usize,
+    /// Whether to draw the terminal index next to each row.
+    pub show_labels: bool,
+    /// Restricts which connections are drawn at all.
+    pub filter: ConnectionFilter,
+    /// Selects which of the drawn connections are highlighted.
+    pub highlight: Highlight,
+    /// Stroke color used for highlighted connections.
+    pub highlight_stroke_color: String,
+    /// Stroke width used for highlighted connections.
+    pub highlight_stroke_width: usize,
+    /// Stroke opacity used for connections that do not match
+    /// `highlight`, whenever `highlight` is not [`Highlight::None`].
+    /// Dims the rest of the drawing so the highlighted connections
+    /// stand out.
+    pub dim_stroke_opacity: f64,
+}
+
+impl Default for RenderConfig {
+    fn default() -> Self {
+        Self {
+            block_w: 20,
+            block_h: 20,
+            margin_x: 40,
+            margin_y: 40,
+            left_text_pad: 30,
+            stroke_color: "black".to_string(),
+            frame_stroke_color: "#444444".to_string(),
+            fill_color: "#ffffff".to_string(),
+            text_color: "#000000".to_string(),
+            stroke_width: 2,
+            font_family: "sans-serif".to_string(),
+            font_size: 20,
+            show_labels: true,
+            filter: ConnectionFilter::All,
+            highlight: Highlight::None,
+            highlight_stroke_color: "#ff0000".to_string(),
+            highlight_stroke_width: 3,
+            dim_stroke_opacity: 0.25,
+        }
+    }
+}
+
+/// Restricts which connections [`Crossbar::render`] draws.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConnectionFilter {
+    /// Draw every connection.
+    All,
+    /// Draw only the connections in the given column.
+    Column(usize),
+    /// Draw only the connections in the given block.
+    Block(usize),
+}
+
+impl ConnectionFilter {
+    fn matches(&self, con: &Connection) -> bool {
+        match self {
+            ConnectionFilter::All => true,
+            ConnectionFilter::Column(col_idx) => con.col_idx == *col_idx,
+            ConnectionFilter::Block(block_idx) => {
+                con.start.block_idx == *block_idx || con.end.block_idx == *block_idx
+            }
+        }
+    }
+}
+
+/// Selects which connections [`Crossbar::render`] draws with the
+/// highlight style instead of the regular one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Highlight {
+    /// Highlight nothing.
+    None,
+    /// Highlight the connections in the given column.
+    Column(usize),
+    /// Highlight the connections in the given block.
+    Block(usize),
+    /// Highlight the connections realizing the given terminal edges.
+    Edges(Vec<(usize, usize)>),
+}
+
+impl Highlight {
+    fn matches(&self, con: &Connection) -> bool {
+        match self {
+            Highlight::None => false,
+            Highlight::Column(col_idx) => con.col_idx == *col_idx,
+            Highlight::Block(block_idx) => {
+                con.start.block_idx == *block_idx || con.end.block_idx == *block_idx
+            }
+            Highlight::Edges(edges) => edges.iter().any(|&(a, b)| {
+                (con.start.row_idx == a && con.end.row_idx == b)
+                    || (con.start.row_idx == b && con.end.row_idx == a)
+            }),
+        }
+    }
+}
+
+impl Crossbar {
+    /// Renders the crossbar switch as an SVG [`Document`].
+    ///
+    /// # Arguments
+    ///
+    /// * `cfg` - Rendering options (dimensions, colors, font, ...).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use xbar::{Crossbar, RenderConfig};
+    /// let doc = Crossbar::new(5).render(&RenderConfig::default());
+    /// ```
+    pub fn render(&self, cfg: &RenderConfig) -> Document {
+        let n = self.count;
+        let w =
+            Self::columns(n) * cfg.block_w + cfg.block_w + 2 * cfg.margin_x + cfg.left_text_pad;
+        let h = (Self::rows(n) + Self::blocks(n) - 1) * cfg.block_h + 2 * cfg.margin_y;
+        let mut doc = Document::new().set("viewBox", (0, 0, w, h)).add(
+            Path::new()
+                .set("fill", cfg.fill_color.as_str())
+                .set("stroke", cfg.frame_stroke_color.as_str())
+                .set("stroke-width", cfg.stroke_width)
+                .set(
+                    "d",
+                    Data::new()
+                        .move_to((0, 0))
+                        .line_to((w, 0))
+                        .line_to((w, h))
+                        .line_to((0, h))
+                        .close(),
+                ),
+        );
+        for val in Self::new(n) {
+            if cfg.filter.matches(&val) {
+                doc = render_one(doc, &val, n, cfg);
+            }
+        }
+        doc
+    }
+
+    /// Renders the crossbar switch and writes the resulting SVG
+    /// document to `writer`.
+    ///
+    /// # Arguments
+    ///
+    /// * `cfg` - Rendering options (dimensions, colors, font, ...).
+    /// * `writer` - Destination the SVG document is written to.
+    pub fn render_to_writer<W: Write>(&self, cfg: &RenderConfig, writer: W) -> io::Result<()> {
+        svg::write(writer, &self.render(cfg))
+    }
+}
+
+fn render_one(doc: Document, val: &Connection, n: usize, cfg: &RenderConfig) -> Document {
+    let l0 = cfg.margin_x + cfg.left_text_pad;
+    let l1 = l0 + (1 + val.col_idx) * cfg.block_w;
+    let t0 = cfg.margin_y + cfg.block_h * (val.start.block_idx * (n + 1) + val.start.row_idx);
+    let t1 = cfg.margin_y + cfg.block_h * (val.end.block_idx * (n + 1) + val.end.row_idx);
+    let highlight_active = cfg.highlight != Highlight::None;
+    let is_highlighted = cfg.highlight.matches(val);
+    let (stroke, stroke_width) = if is_highlighted {
+        (cfg.highlight_stroke_color.as_str(), cfg.highlight_stroke_width)
+    } else {
+        (cfg.stroke_color.as_str(), cfg.stroke_width)
+    };
+    let mut path = Path::new()
+        .set("fill", "none")
+        .set("stroke", stroke)
+        .set("stroke-width", stroke_width);
+    if highlight_active && !is_highlighted {
+        path = path.set("stroke-opacity", cfg.dim_stroke_opacity);
+    }
+    let mut doc = doc.add(path.set(
+        "d",
+        Data::new()
+            .move_to((l0, t0))
+            .line_to((l1, t0))
+            .line_to((l1, t1))
+            .line_to((l0, t1)),
+    ));
+    if cfg.show_labels {
+        doc = doc
+            .add(text_label(t0, val.start.row_idx, cfg))
+            .add(text_label(t1, val.end.row_idx, cfg));
+    }
+    doc
+}
+
+fn text_label(top: usize, row_idx: usize, cfg: &RenderConfig) -> TextBox {
+    TextBox::new()
+        .set("y", top + cfg.block_h / 4)
+        .set("x", cfg.margin_x)
+        .set(
+            "style",
+            format!(
+                "font-size:{}px; \
+                 font-family:{}; \
+                 fill:{}; \
+                 fill-opacity:1; \
+                 stroke:none;",
+                cfg.font_size, cfg.font_family, cfg.text_color
+            ),
+        )
+        .add(Text::new(format!("{}", row_idx)))
+}